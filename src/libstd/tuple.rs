@@ -14,8 +14,8 @@
 
 use kinds::Copy;
 use vec;
-use vec::ImmutableVector;
-use iterator::IteratorUtil;
+use vec::{ImmutableVector, VecIterator};
+use iterator::{IteratorUtil, ZipIterator};
 
 pub use self::inner::*;
 
@@ -82,6 +82,9 @@ impl<T, U> ImmutableTuple<T, U> for (T, U) {
 pub trait ExtendedTupleOps<A,B> {
     fn zip(&self) -> ~[(A, B)];
     fn map<C>(&self, f: &fn(a: &A, b: &B) -> C) -> ~[C];
+    fn unzip(&self, pairs: &[(A, B)]) -> (~[A], ~[B]);
+    fn foldl<C>(&self, init: C, f: &fn(acc: C, a: &A, b: &B) -> C) -> C;
+    fn zip_iter<'a>(&'a self) -> ZipIterator<VecIterator<'a, A>, VecIterator<'a, B>>;
 }
 
 impl<'self,A:Copy,B:Copy> ExtendedTupleOps<A,B> for (&'self [A], &'self [B]) {
@@ -102,6 +105,31 @@ impl<'self,A:Copy,B:Copy> ExtendedTupleOps<A,B> for (&'self [A], &'self [B]) {
             }
         }
     }
+
+    #[inline]
+    fn unzip(&self, pairs: &[(A, B)]) -> (~[A], ~[B]) {
+        vec::unzip_slice(pairs)
+    }
+
+    #[inline]
+    fn foldl<C>(&self, init: C, f: &fn(acc: C, a: &A, b: &B) -> C) -> C {
+        match *self {
+            (ref a, ref b) => {
+                let mut accum = init;
+                for a.iter().zip(b.iter()).advance |(aa, bb)| {
+                    accum = f(accum, aa, bb);
+                }
+                accum
+            }
+        }
+    }
+
+    #[inline]
+    fn zip_iter<'a>(&'a self) -> ZipIterator<VecIterator<'a, A>, VecIterator<'a, B>> {
+        match *self {
+            (ref a, ref b) => a.iter().zip(b.iter())
+        }
+    }
 }
 
 impl<A:Copy,B:Copy> ExtendedTupleOps<A,B> for (~[A], ~[B]) {
@@ -122,6 +150,31 @@ impl<A:Copy,B:Copy> ExtendedTupleOps<A,B> for (~[A], ~[B]) {
             }
         }
     }
+
+    #[inline]
+    fn unzip(&self, pairs: &[(A, B)]) -> (~[A], ~[B]) {
+        vec::unzip_slice(pairs)
+    }
+
+    #[inline]
+    fn foldl<C>(&self, init: C, f: &fn(acc: C, a: &A, b: &B) -> C) -> C {
+        match *self {
+            (ref a, ref b) => {
+                let mut accum = init;
+                for a.iter().zip(b.iter()).advance |(aa, bb)| {
+                    accum = f(accum, aa, bb);
+                }
+                accum
+            }
+        }
+    }
+
+    #[inline]
+    fn zip_iter<'a>(&'a self) -> ZipIterator<VecIterator<'a, A>, VecIterator<'a, B>> {
+        match *self {
+            (ref a, ref b) => a.iter().zip(b.iter())
+        }
+    }
 }
 
 // macro for implementing n-ary tuple functions and operations
@@ -138,6 +191,7 @@ macro_rules! tuple_impls {
             use clone::Clone;
             #[cfg(not(test))] use cmp::*;
             #[cfg(not(test))] use num::Zero;
+            #[cfg(not(test))] use to_bytes::{IterBytes, Cb};
 
             $(
                 pub trait $cloneable_trait<$($T),+> {
@@ -225,6 +279,14 @@ macro_rules! tuple_impls {
                         $(self.$get_ref_fn().is_zero())&&+
                     }
                 }
+
+                #[cfg(not(test))]
+                impl<$($T:IterBytes),+> IterBytes for ($($T),+) {
+                    #[inline]
+                    fn iter_bytes(&self, lsb0: bool, f: Cb) -> bool {
+                        $(self.$get_ref_fn().iter_bytes(lsb0, f))&&+
+                    }
+                }
             )+
         }
     }
@@ -253,6 +315,13 @@ macro_rules! lexical_cmp {
     ($a:expr, $b:expr) => { ($a).cmp($b) };
 }
 
+// A homogeneous-tuple view (`as_slice`/`iter` over `(T, T, ...)`) was
+// requested, but it cannot be provided soundly: a zero-copy slice/iterator
+// bridge would require the tuple's fields to share the layout of `[T, ..N]`,
+// and the language makes no such guarantee (fields may be reordered or
+// padded), so the only implementation is a layout-assuming transmute that is
+// undefined behaviour. Exposing a `len()`-only trait delivers none of the
+// sequence operations the request was about, so the view is omitted entirely.
 
 tuple_impls! {
     (CloneableTuple2, ImmutableTuple2) {
@@ -425,6 +494,40 @@ mod tests {
         assert_eq!(t.n11_ref(), &11f64);
     }
 
+    #[test]
+    fn test_tuple_iter_bytes() {
+        use to_bytes::IterBytes;
+
+        let mut bytes = ~[];
+        do (1u8, 2u8, 3u8).iter_bytes(true) |buf| {
+            bytes.push_all(buf);
+            true
+        };
+        assert_eq!(bytes, ~[1u8, 2u8, 3u8]);
+    }
+
+    #[test]
+    fn test_extended_tuple_ops() {
+        let a = ~[1, 2, 3];
+        let b = ~[4, 5, 6];
+
+        let sum = (a.clone(), b.clone()).foldl(0, |acc, x, y| acc + *x + *y);
+        assert_eq!(sum, 21);
+
+        let pairs = (a.clone(), b.clone()).zip();
+        let (xs, ys) = (a.clone(), b.clone()).unzip(pairs);
+        assert_eq!(xs, a);
+        assert_eq!(ys, b);
+
+        let mut n = 0;
+        let t = (a.clone(), b.clone());
+        for t.zip_iter().advance |(x, y)| {
+            assert_eq!(*x + 3, *y);
+            n += 1;
+        }
+        assert_eq!(n, 3);
+    }
+
     #[test]
     fn test_tuple_cmp() {
         let (small, big) = ((1u, 2u, 3u), (3u, 2u, 1u));